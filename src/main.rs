@@ -1,15 +1,84 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Point {
     pub x: f64,
     pub y: f64,
     pub z: f64,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Vector {
     pub dx: f64,
     pub dy: f64,
     pub dz: f64,
 }
 
+impl Vector {
+    pub fn dot(&self, other: &Vector) -> f64 {
+        self.dx * other.dx + self.dy * other.dy + self.dz * other.dz
+    }
+
+    pub fn cross(&self, other: &Vector) -> Vector {
+        Vector {
+            dx: self.dy * other.dz - self.dz * other.dy,
+            dy: self.dz * other.dx - self.dx * other.dz,
+            dz: self.dx * other.dy - self.dy * other.dx,
+        }
+    }
+
+    pub fn length(&self) -> f64 {
+        self.dot(self).sqrt()
+    }
+
+    pub fn normalize(&self) -> Vector {
+        *self / self.length()
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+    fn add(self, rhs: Vector) -> Vector {
+        Vector { dx: self.dx + rhs.dx, dy: self.dy + rhs.dy, dz: self.dz + rhs.dz }
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+    fn sub(self, rhs: Vector) -> Vector {
+        Vector { dx: self.dx - rhs.dx, dy: self.dy - rhs.dy, dz: self.dz - rhs.dz }
+    }
+}
+
+impl Mul<f64> for Vector {
+    type Output = Vector;
+    fn mul(self, scalar: f64) -> Vector {
+        Vector { dx: self.dx * scalar, dy: self.dy * scalar, dz: self.dz * scalar }
+    }
+}
+
+impl Div<f64> for Vector {
+    type Output = Vector;
+    fn div(self, scalar: f64) -> Vector {
+        Vector { dx: self.dx / scalar, dy: self.dy / scalar, dz: self.dz / scalar }
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+    fn sub(self, rhs: Point) -> Vector {
+        Vector { dx: self.x - rhs.x, dy: self.y - rhs.y, dz: self.z - rhs.z }
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+    fn add(self, rhs: Vector) -> Point {
+        Point { x: self.x + rhs.dx, y: self.y + rhs.dy, z: self.z + rhs.dz }
+    }
+}
+
 pub enum CSGSurface {
     Sphere { x: f64, y: f64, z: f64, radius: f64 },
     XPlane { x: f64 },
@@ -24,15 +93,267 @@ pub enum CSGSurface {
     ZAxisCone { x: f64, y: f64, z: f64, angle: f64 }, // angle in radians
     Quadric { a: f64, b: f64, c: f64, d: f64, e: f64, f: f64, g: f64, h: f64, j: f64, k: f64 }, // Ax^2 + By^2 + Cz^2 + Dxy + Eyz + Fxz + Gx + Hy + Jz + K = 0
     XAxisTorus { x0: f64, y0: f64, z0: f64, a: f64, b: f64, c: f64 }, // Torus parallel to x-axis
+    YAxisTorus { x0: f64, y0: f64, z0: f64, a: f64, b: f64, c: f64 }, // Torus parallel to y-axis
+    ZAxisTorus { x0: f64, y0: f64, z0: f64, a: f64, b: f64, c: f64 }, // Torus parallel to z-axis
+}
+
+// Floating point primitives used by the distance/root-solving code below,
+// gated behind the `libm` feature so builds that need bit-reproducible
+// results across hosts and compilers can opt into `libm`'s portable
+// implementations instead of the platform's native `f64` intrinsics.
+mod ops {
+    #[cfg(not(feature = "libm"))]
+    pub fn sqrt(x: f64) -> f64 {
+        x.sqrt()
+    }
+    #[cfg(feature = "libm")]
+    pub fn sqrt(x: f64) -> f64 {
+        libm::sqrt(x)
+    }
+
+    #[cfg(not(feature = "libm"))]
+    pub fn cbrt(x: f64) -> f64 {
+        x.cbrt()
+    }
+    #[cfg(feature = "libm")]
+    pub fn cbrt(x: f64) -> f64 {
+        libm::cbrt(x)
+    }
+
+    #[cfg(not(feature = "libm"))]
+    pub fn tan(x: f64) -> f64 {
+        x.tan()
+    }
+    #[cfg(feature = "libm")]
+    pub fn tan(x: f64) -> f64 {
+        libm::tan(x)
+    }
+
+    #[cfg(not(feature = "libm"))]
+    pub fn cos(x: f64) -> f64 {
+        x.cos()
+    }
+    #[cfg(feature = "libm")]
+    pub fn cos(x: f64) -> f64 {
+        libm::cos(x)
+    }
+
+    #[cfg(not(feature = "libm"))]
+    pub fn acos(x: f64) -> f64 {
+        x.acos()
+    }
+    #[cfg(feature = "libm")]
+    pub fn acos(x: f64) -> f64 {
+        libm::acos(x)
+    }
+
+    #[cfg(not(feature = "libm"))]
+    pub fn powi(x: f64, n: i32) -> f64 {
+        x.powi(n)
+    }
+    #[cfg(feature = "libm")]
+    pub fn powi(x: f64, n: i32) -> f64 {
+        // libm has no powi; mirror f64::powi's contract (including negative
+        // exponents) via repeated multiplication rather than just the
+        // positive case every current call site happens to use.
+        if n < 0 {
+            1.0 / powi(x, -n)
+        } else {
+            (0..n).fold(1.0, |acc, _| acc * x)
+        }
+    }
+}
+
+// Smallest strictly-positive root of a*t^2 + b*t + c = 0, falling back to the
+// linear solve when a is degenerate. Points sitting on the surface (t ~ 0)
+// are skipped so particles don't get stuck re-crossing their own origin.
+fn smallest_positive_root(a: f64, b: f64, c: f64) -> Option<f64> {
+    const ROOT_EPSILON: f64 = 1e-9;
+
+    if a.abs() < ROOT_EPSILON {
+        if b.abs() < ROOT_EPSILON {
+            return None;
+        }
+        let t = -c / b;
+        return if t > ROOT_EPSILON { Some(t) } else { None };
+    }
+
+    let discriminant = b * b - 4.0 * a * c;
+    if discriminant < 0.0 {
+        return None;
+    }
+    let sqrt_discriminant = ops::sqrt(discriminant);
+    let t1 = (-b - sqrt_discriminant) / (2.0 * a);
+    let t2 = (-b + sqrt_discriminant) / (2.0 * a);
+    let (t_min, t_max) = if t1 <= t2 { (t1, t2) } else { (t2, t1) };
+
+    if t_min > ROOT_EPSILON {
+        Some(t_min)
+    } else if t_max > ROOT_EPSILON {
+        Some(t_max)
+    } else {
+        None
+    }
+}
+
+// Real roots of a*t^3 + b*t^2 + c*t + d = 0 via Cardano's formula, depressing
+// the cubic and branching on the sign of its discriminant.
+fn solve_cubic(a: f64, b: f64, c: f64, d: f64) -> Vec<f64> {
+    const EPSILON: f64 = 1e-9;
+
+    let b = b / a;
+    let c = c / a;
+    let d = d / a;
+    let b_sq = b * b;
+    let p = c - b_sq / 3.0;
+    let q = 2.0 * b_sq * b / 27.0 - b * c / 3.0 + d;
+
+    let mut roots = Vec::new();
+    if p.abs() < EPSILON {
+        roots.push(ops::cbrt(-q));
+    } else {
+        let discriminant = ops::powi(q / 2.0, 2) + ops::powi(p / 3.0, 3);
+        if discriminant > EPSILON {
+            let sqrt_discriminant = ops::sqrt(discriminant);
+            let u = ops::cbrt(-q / 2.0 + sqrt_discriminant);
+            let v = ops::cbrt(-q / 2.0 - sqrt_discriminant);
+            roots.push(u + v);
+        } else if discriminant.abs() <= EPSILON {
+            let u = ops::cbrt(-q / 2.0);
+            roots.push(2.0 * u);
+            roots.push(-u);
+        } else {
+            // Three distinct real roots: trigonometric form.
+            let r = ops::sqrt(-ops::powi(p / 3.0, 3));
+            let phi = ops::acos((-q / (2.0 * r)).clamp(-1.0, 1.0));
+            let m = 2.0 * ops::sqrt(-p / 3.0);
+            for k in 0..3 {
+                let angle = (phi + 2.0 * std::f64::consts::PI * k as f64) / 3.0;
+                roots.push(m * ops::cos(angle));
+            }
+        }
+    }
+    roots.into_iter().map(|y| y - b / 3.0).collect()
+}
+
+// Real roots of the depressed quartic y^4 + p*y^2 + q*y + r = 0 via Ferrari's
+// method: a biquadratic shortcut when q is negligible, otherwise a resolvent
+// cubic picks the factorization into two real quadratics.
+fn solve_depressed_quartic(p: f64, q: f64, r: f64) -> Vec<f64> {
+    const EPSILON: f64 = 1e-7;
+    let mut roots = Vec::new();
+
+    if q.abs() < EPSILON {
+        let discriminant = p * p - 4.0 * r;
+        if discriminant >= 0.0 {
+            let sqrt_discriminant = ops::sqrt(discriminant);
+            for z in [(-p + sqrt_discriminant) / 2.0, (-p - sqrt_discriminant) / 2.0] {
+                if z >= 0.0 {
+                    let y = ops::sqrt(z);
+                    roots.push(y);
+                    roots.push(-y);
+                }
+            }
+        }
+        return roots;
+    }
+
+    // m is a real root of the resolvent cubic 8m^3+8p*m^2+(2p^2-8r)*m-q^2=0;
+    // the largest real root is the one that makes 2m (and hence sqrt_term)
+    // non-negative for the factorization below.
+    let resolvent_roots = solve_cubic(8.0, 8.0 * p, 2.0 * p * p - 8.0 * r, -q * q);
+    let m = resolvent_roots
+        .into_iter()
+        .fold(None, |acc: Option<f64>, m| Some(acc.map_or(m, |best: f64| best.max(m))));
+    let m = match m {
+        Some(m) => m,
+        None => return roots,
+    };
+
+    if m < EPSILON {
+        return roots;
+    }
+
+    let sqrt_term = ops::sqrt(2.0 * m);
+    let cross_term = q / (2.0 * sqrt_term);
+    let half_p = p / 2.0;
+    for (b, c) in [(-sqrt_term, half_p + m + cross_term), (sqrt_term, half_p + m - cross_term)] {
+        let discriminant = b * b - 4.0 * c;
+        if discriminant >= 0.0 {
+            let sqrt_discriminant = ops::sqrt(discriminant);
+            roots.push((-b + sqrt_discriminant) / 2.0);
+            roots.push((-b - sqrt_discriminant) / 2.0);
+        }
+    }
+    roots
+}
+
+// Real roots of the general quartic t^4 + p*t^3 + q*t^2 + r*t + s = 0.
+fn solve_quartic(p: f64, q: f64, r: f64, s: f64) -> Vec<f64> {
+    let depressed_p = q - 3.0 * p * p / 8.0;
+    let depressed_q = r - p * q / 2.0 + ops::powi(p, 3) / 8.0;
+    let depressed_r = s - p * r / 4.0 + p * p * q / 16.0 - 3.0 * ops::powi(p, 4) / 256.0;
+    solve_depressed_quartic(depressed_p, depressed_q, depressed_r)
+        .into_iter()
+        .map(|y| y - p / 4.0)
+        .collect()
+}
+
+// Smallest strictly-positive value in `roots`, skipping roots at (or behind) the origin.
+fn smallest_positive(roots: &[f64]) -> Option<f64> {
+    const ROOT_EPSILON: f64 = 1e-9;
+    roots
+        .iter()
+        .copied()
+        .filter(|t| *t > ROOT_EPSILON)
+        .fold(None, |acc, t| Some(acc.map_or(t, |best: f64| best.min(t))))
+}
+
+// Ray-vs-torus intersection shared by the three axis-aligned torus variants.
+// `dist_coeffs` are the quadratic-in-t coefficients (t^2, t^1, t^0) of
+// |P(t) - center|^2 and `perp_coeffs` are the same for the two components
+// perpendicular to the torus axis; `major_radius`/`minor_radius` are the
+// torus's `a` and `c`.
+fn torus_distance_to_surface(
+    dist_coeffs: (f64, f64, f64),
+    perp_coeffs: (f64, f64, f64),
+    major_radius: f64,
+    minor_radius: f64,
+) -> Option<f64> {
+    let (a2, a1, a0) = dist_coeffs;
+    let (b2, b1, b0) = perp_coeffs;
+
+    const DEGENERATE_EPSILON: f64 = 1e-12;
+    if a2.abs() < DEGENERATE_EPSILON {
+        return None;
+    }
+
+    let q = major_radius * major_radius - minor_radius * minor_radius;
+    let four_a2 = 4.0 * major_radius * major_radius;
+
+    let c4 = a2 * a2;
+    let c3 = 2.0 * a2 * a1;
+    let c2 = a1 * a1 + 2.0 * a2 * a0 + 2.0 * q * a2 - four_a2 * b2;
+    let c1 = 2.0 * a1 * a0 + 2.0 * q * a1 - four_a2 * b1;
+    let c0 = a0 * a0 + 2.0 * q * a0 + q * q - four_a2 * b0;
+
+    let roots = solve_quartic(c3 / c4, c2 / c4, c1 / c4, c0 / c4);
+    smallest_positive(&roots)
 }
 
+
 impl CSGSurface {
+    /// Distance traveled along `vector` from `point` to the next surface
+    /// crossing, for use in particle tracking. Returns `None` if the ray
+    /// never crosses the surface.
     pub fn distance_to_surface(&self, point: &Point, vector: &Vector) -> Option<f64> {
         match self {
             CSGSurface::Sphere { x, y, z, radius } => {
-                let distance_to_center = ((point.x - x).powi(2) + (point.y - y).powi(2) + (point.z - z).powi(2)).sqrt();
-                let distance_to_surface = (distance_to_center - radius).abs();
-                Some(distance_to_surface)
+                let delta = *point - Point { x: *x, y: *y, z: *z };
+                let a = vector.dot(vector);
+                let b = 2.0 * vector.dot(&delta);
+                let c = delta.dot(&delta) - ops::powi(*radius, 2);
+                smallest_positive_root(a, b, c)
             }
             CSGSurface::XPlane { x } => {
                 if vector.dx == 0.0 {
@@ -71,57 +392,327 @@ impl CSGSurface {
                 }
             }
             CSGSurface::Plane { a, b, c, d } => {
+                let normal = Vector { dx: *a, dy: *b, dz: *c };
+                let denominator = vector.dot(&normal);
+                if denominator == 0.0 {
+                    None
+                } else {
+                    let numerator = a * point.x + b * point.y + c * point.z + d;
+                    let t = -numerator / denominator;
+                    if t >= 0.0 {
+                        Some(t)
+                    } else {
+                        None
+                    }
+                }
+            }
+            CSGSurface::XAxisCylinder { radius } => {
+                let perp_point = Vector { dx: 0.0, dy: point.y, dz: point.z };
+                let perp_dir = Vector { dx: 0.0, dy: vector.dy, dz: vector.dz };
+                let a = perp_dir.dot(&perp_dir);
+                let b = 2.0 * perp_dir.dot(&perp_point);
+                let c = perp_point.dot(&perp_point) - ops::powi(*radius, 2);
+                smallest_positive_root(a, b, c)
+            }
+            CSGSurface::YAxisCylinder { radius } => {
+                let perp_point = Vector { dx: point.x, dy: 0.0, dz: point.z };
+                let perp_dir = Vector { dx: vector.dx, dy: 0.0, dz: vector.dz };
+                let a = perp_dir.dot(&perp_dir);
+                let b = 2.0 * perp_dir.dot(&perp_point);
+                let c = perp_point.dot(&perp_point) - ops::powi(*radius, 2);
+                smallest_positive_root(a, b, c)
+            }
+            CSGSurface::ZAxisCylinder { radius } => {
+                let perp_point = Vector { dx: point.x, dy: point.y, dz: 0.0 };
+                let perp_dir = Vector { dx: vector.dx, dy: vector.dy, dz: 0.0 };
+                let a = perp_dir.dot(&perp_dir);
+                let b = 2.0 * perp_dir.dot(&perp_point);
+                let c = perp_point.dot(&perp_point) - ops::powi(*radius, 2);
+                smallest_positive_root(a, b, c)
+            }
+            CSGSurface::XAxisCone { x, y, z, angle } => {
+                let k = ops::powi(ops::tan(*angle), 2);
+                let delta = *point - Point { x: *x, y: *y, z: *z };
+                let perp_delta = Vector { dx: 0.0, dy: delta.dy, dz: delta.dz };
+                let perp_dir = Vector { dx: 0.0, dy: vector.dy, dz: vector.dz };
+                let a = perp_dir.dot(&perp_dir) - k * ops::powi(vector.dx, 2);
+                let b = 2.0 * (perp_dir.dot(&perp_delta) - k * delta.dx * vector.dx);
+                let c = perp_delta.dot(&perp_delta) - k * ops::powi(delta.dx, 2);
+                smallest_positive_root(a, b, c)
+            }
+            CSGSurface::YAxisCone { x, y, z, angle } => {
+                let k = ops::powi(ops::tan(*angle), 2);
+                let delta = *point - Point { x: *x, y: *y, z: *z };
+                let perp_delta = Vector { dx: delta.dx, dy: 0.0, dz: delta.dz };
+                let perp_dir = Vector { dx: vector.dx, dy: 0.0, dz: vector.dz };
+                let a = perp_dir.dot(&perp_dir) - k * ops::powi(vector.dy, 2);
+                let b = 2.0 * (perp_dir.dot(&perp_delta) - k * delta.dy * vector.dy);
+                let c = perp_delta.dot(&perp_delta) - k * ops::powi(delta.dy, 2);
+                smallest_positive_root(a, b, c)
+            }
+            CSGSurface::ZAxisCone { x, y, z, angle } => {
+                let k = ops::powi(ops::tan(*angle), 2);
+                let delta = *point - Point { x: *x, y: *y, z: *z };
+                let perp_delta = Vector { dx: delta.dx, dy: delta.dy, dz: 0.0 };
+                let perp_dir = Vector { dx: vector.dx, dy: vector.dy, dz: 0.0 };
+                let a = perp_dir.dot(&perp_dir) - k * ops::powi(vector.dz, 2);
+                let b = 2.0 * (perp_dir.dot(&perp_delta) - k * delta.dz * vector.dz);
+                let c = perp_delta.dot(&perp_delta) - k * ops::powi(delta.dz, 2);
+                smallest_positive_root(a, b, c)
+            }
+            CSGSurface::Quadric { a, b, c, d, e, f, g, h, j, k } => {
+                let px = point.x;
+                let py = point.y;
+                let pz = point.z;
+                let dx = vector.dx;
+                let dy = vector.dy;
+                let dz = vector.dz;
+
+                let alpha = a * ops::powi(dx, 2) + b * ops::powi(dy, 2) + c * ops::powi(dz, 2) +
+                            d * dx * dy + e * dy * dz + f * dx * dz;
+                let beta = 2.0 * a * px * dx + 2.0 * b * py * dy + 2.0 * c * pz * dz +
+                           d * (px * dy + py * dx) + e * (py * dz + pz * dy) + f * (px * dz + pz * dx) +
+                           g * dx + h * dy + j * dz;
+                let gamma = a * ops::powi(px, 2) + b * ops::powi(py, 2) + c * ops::powi(pz, 2) +
+                            d * px * py + e * py * pz + f * px * pz +
+                            g * px + h * py + j * pz + k;
+                smallest_positive_root(alpha, beta, gamma)
+            }
+            CSGSurface::XAxisTorus { x0, y0, z0, a, b: _, c } => {
+                let delta = *point - Point { x: *x0, y: *y0, z: *z0 };
+                let perp_delta = Vector { dx: 0.0, dy: delta.dy, dz: delta.dz };
+                let perp_dir = Vector { dx: 0.0, dy: vector.dy, dz: vector.dz };
+                let a2 = vector.dot(vector);
+                let a1 = 2.0 * vector.dot(&delta);
+                let a0 = delta.dot(&delta);
+                let b2 = perp_dir.dot(&perp_dir);
+                let b1 = 2.0 * perp_dir.dot(&perp_delta);
+                let b0 = perp_delta.dot(&perp_delta);
+                torus_distance_to_surface((a2, a1, a0), (b2, b1, b0), *a, *c)
+            }
+            CSGSurface::YAxisTorus { x0, y0, z0, a, b: _, c } => {
+                let delta = *point - Point { x: *x0, y: *y0, z: *z0 };
+                let perp_delta = Vector { dx: delta.dx, dy: 0.0, dz: delta.dz };
+                let perp_dir = Vector { dx: vector.dx, dy: 0.0, dz: vector.dz };
+                let a2 = vector.dot(vector);
+                let a1 = 2.0 * vector.dot(&delta);
+                let a0 = delta.dot(&delta);
+                let b2 = perp_dir.dot(&perp_dir);
+                let b1 = 2.0 * perp_dir.dot(&perp_delta);
+                let b0 = perp_delta.dot(&perp_delta);
+                torus_distance_to_surface((a2, a1, a0), (b2, b1, b0), *a, *c)
+            }
+            CSGSurface::ZAxisTorus { x0, y0, z0, a, b: _, c } => {
+                let delta = *point - Point { x: *x0, y: *y0, z: *z0 };
+                let perp_delta = Vector { dx: delta.dx, dy: delta.dy, dz: 0.0 };
+                let perp_dir = Vector { dx: vector.dx, dy: vector.dy, dz: 0.0 };
+                let a2 = vector.dot(vector);
+                let a1 = 2.0 * vector.dot(&delta);
+                let a0 = delta.dot(&delta);
+                let b2 = perp_dir.dot(&perp_dir);
+                let b1 = 2.0 * perp_dir.dot(&perp_delta);
+                let b0 = perp_delta.dot(&perp_delta);
+                torus_distance_to_surface((a2, a1, a0), (b2, b1, b0), *a, *c)
+            }
+        }
+    }
+
+    /// Unsigned distance from `point` to the nearest point on the surface,
+    /// independent of any travel direction. Useful for geometry queries
+    /// (e.g. "how close am I to this surface?") where `distance_to_surface`'s
+    /// ray crossing isn't what's needed.
+    ///
+    /// This holds for spheres, planes, cylinders and tori. For the cones the
+    /// value is `| |axial offset from apex| - radial offset * tan(angle) |`,
+    /// which is not the Euclidean distance and, unless `angle` is pi/4, is not
+    /// zero on the cone that `distance_to_surface` and `evaluate` use. For
+    /// `Quadric` it is the absolute value of the implicit polynomial at
+    /// `point`, not a distance.
+    pub fn nearest_distance(&self, point: &Point) -> f64 {
+        match self {
+            CSGSurface::Sphere { x, y, z, radius } => {
+                let distance_to_center = (*point - Point { x: *x, y: *y, z: *z }).length();
+                (distance_to_center - radius).abs()
+            }
+            CSGSurface::XPlane { x } => (x - point.x).abs(),
+            CSGSurface::YPlane { y } => (y - point.y).abs(),
+            CSGSurface::ZPlane { z } => (z - point.z).abs(),
+            CSGSurface::Plane { a, b, c, d } => {
+                let normal = Vector { dx: *a, dy: *b, dz: *c };
                 let numerator = a * point.x + b * point.y + c * point.z + d;
-                let denominator = (a.powi(2) + b.powi(2) + c.powi(2)).sqrt();
-                Some((numerator / denominator).abs())
+                (numerator / normal.length()).abs()
             }
             CSGSurface::XAxisCylinder { radius } => {
-                let distance_to_axis = (point.y.powi(2) + point.z.powi(2)).sqrt();
-                Some((distance_to_axis - radius).abs())
+                let perp = Vector { dx: 0.0, dy: point.y, dz: point.z };
+                (perp.length() - radius).abs()
             }
             CSGSurface::YAxisCylinder { radius } => {
-                let distance_to_axis = (point.x.powi(2) + point.z.powi(2)).sqrt();
-                Some((distance_to_axis - radius).abs())
+                let perp = Vector { dx: point.x, dy: 0.0, dz: point.z };
+                (perp.length() - radius).abs()
             }
             CSGSurface::ZAxisCylinder { radius } => {
-                let distance_to_axis = (point.x.powi(2) + point.y.powi(2)).sqrt();
-                Some((distance_to_axis - radius).abs())
+                let perp = Vector { dx: point.x, dy: point.y, dz: 0.0 };
+                (perp.length() - radius).abs()
             }
             CSGSurface::XAxisCone { x, y, z, angle } => {
                 let tan_angle = angle.tan();
-                let distance_to_apex = ((point.y - y).powi(2) + (point.z - z).powi(2)).sqrt();
-                let distance_to_surface = ((point.x - x).abs() - distance_to_apex * tan_angle).abs();
-                Some(distance_to_surface)
+                let delta = *point - Point { x: *x, y: *y, z: *z };
+                let perp = Vector { dx: 0.0, dy: delta.dy, dz: delta.dz };
+                (delta.dx.abs() - perp.length() * tan_angle).abs()
             }
             CSGSurface::YAxisCone { x, y, z, angle } => {
                 let tan_angle = angle.tan();
-                let distance_to_apex = ((point.x - x).powi(2) + (point.z - z).powi(2)).sqrt();
-                let distance_to_surface = ((point.y - y).abs() - distance_to_apex * tan_angle).abs();
-                Some(distance_to_surface)
+                let delta = *point - Point { x: *x, y: *y, z: *z };
+                let perp = Vector { dx: delta.dx, dy: 0.0, dz: delta.dz };
+                (delta.dy.abs() - perp.length() * tan_angle).abs()
             }
             CSGSurface::ZAxisCone { x, y, z, angle } => {
                 let tan_angle = angle.tan();
-                let distance_to_apex = ((point.x - x).powi(2) + (point.y - y).powi(2)).sqrt();
-                let distance_to_surface = ((point.z - z).abs() - distance_to_apex * tan_angle).abs();
-                Some(distance_to_surface)
+                let delta = *point - Point { x: *x, y: *y, z: *z };
+                let perp = Vector { dx: delta.dx, dy: delta.dy, dz: 0.0 };
+                (delta.dz.abs() - perp.length() * tan_angle).abs()
             }
             CSGSurface::Quadric { a, b, c, d, e, f, g, h, j, k } => {
-                // This is a simplified approach and may not be accurate for all cases
                 let value = a * point.x.powi(2) + b * point.y.powi(2) + c * point.z.powi(2) +
                             d * point.x * point.y + e * point.y * point.z + f * point.x * point.z +
                             g * point.x + h * point.y + j * point.z + k;
-                Some(value.abs())
+                value.abs()
+            }
+            CSGSurface::XAxisTorus { x0, y0, z0, a, b: _, c } => {
+                let delta = *point - Point { x: *x0, y: *y0, z: *z0 };
+                let perp = Vector { dx: 0.0, dy: delta.dy, dz: delta.dz };
+                let distance_to_center = (perp.length() - a).abs();
+                (Vector { dx: delta.dx, dy: distance_to_center, dz: 0.0 }.length() - c).abs()
             }
-            CSGSurface::XAxisTorus { x0, y0, z0, a, b, c } => {
-                let dx = point.x - x0;
-                let dy = point.y - y0;
-                let dz = point.z - z0;
-                let distance_to_center = ((dy.powi(2) + dz.powi(2)).sqrt() - a).abs();
-                let distance_to_surface = ((distance_to_center.powi(2) + dx.powi(2)).sqrt() - c).abs();
-                Some(distance_to_surface)
+            CSGSurface::YAxisTorus { x0, y0, z0, a, b: _, c } => {
+                let delta = *point - Point { x: *x0, y: *y0, z: *z0 };
+                let perp = Vector { dx: delta.dx, dy: 0.0, dz: delta.dz };
+                let distance_to_center = (perp.length() - a).abs();
+                (Vector { dx: delta.dy, dy: distance_to_center, dz: 0.0 }.length() - c).abs()
+            }
+            CSGSurface::ZAxisTorus { x0, y0, z0, a, b: _, c } => {
+                let delta = *point - Point { x: *x0, y: *y0, z: *z0 };
+                let perp = Vector { dx: delta.dx, dy: delta.dy, dz: 0.0 };
+                let distance_to_center = (perp.length() - a).abs();
+                (Vector { dx: delta.dz, dy: distance_to_center, dz: 0.0 }.length() - c).abs()
             }
         }
     }
+
+    /// Signed implicit value at `point` (negative inside, positive outside,
+    /// zero on the surface) together with the outward unit normal, derived
+    /// from the gradient of the surface's implicit function. This is the
+    /// surface-sense primitive CSG cells (unions/intersections of
+    /// half-spaces) are built on.
+    pub fn evaluate(&self, point: &Point) -> (f64, Vector) {
+        match self {
+            CSGSurface::Sphere { x, y, z, radius } => {
+                let delta = *point - Point { x: *x, y: *y, z: *z };
+                let sense = delta.dot(&delta) - radius.powi(2);
+                (sense, delta.normalize())
+            }
+            CSGSurface::XPlane { x } => {
+                (point.x - x, Vector { dx: 1.0, dy: 0.0, dz: 0.0 })
+            }
+            CSGSurface::YPlane { y } => {
+                (point.y - y, Vector { dx: 0.0, dy: 1.0, dz: 0.0 })
+            }
+            CSGSurface::ZPlane { z } => {
+                (point.z - z, Vector { dx: 0.0, dy: 0.0, dz: 1.0 })
+            }
+            CSGSurface::Plane { a, b, c, d } => {
+                let sense = a * point.x + b * point.y + c * point.z + d;
+                (sense, Vector { dx: *a, dy: *b, dz: *c }.normalize())
+            }
+            CSGSurface::XAxisCylinder { radius } => {
+                let perp = Vector { dx: 0.0, dy: point.y, dz: point.z };
+                let sense = perp.dot(&perp) - radius.powi(2);
+                (sense, perp.normalize())
+            }
+            CSGSurface::YAxisCylinder { radius } => {
+                let perp = Vector { dx: point.x, dy: 0.0, dz: point.z };
+                let sense = perp.dot(&perp) - radius.powi(2);
+                (sense, perp.normalize())
+            }
+            CSGSurface::ZAxisCylinder { radius } => {
+                let perp = Vector { dx: point.x, dy: point.y, dz: 0.0 };
+                let sense = perp.dot(&perp) - radius.powi(2);
+                (sense, perp.normalize())
+            }
+            CSGSurface::XAxisCone { x, y, z, angle } => {
+                let k = angle.tan().powi(2);
+                let delta = *point - Point { x: *x, y: *y, z: *z };
+                let (dx, dy, dz) = (delta.dx, delta.dy, delta.dz);
+                let perp = Vector { dx: 0.0, dy, dz };
+                let sense = perp.dot(&perp) - k * dx.powi(2);
+                (sense, Vector { dx: -k * dx, dy, dz }.normalize())
+            }
+            CSGSurface::YAxisCone { x, y, z, angle } => {
+                let k = angle.tan().powi(2);
+                let delta = *point - Point { x: *x, y: *y, z: *z };
+                let (dx, dy, dz) = (delta.dx, delta.dy, delta.dz);
+                let perp = Vector { dx, dy: 0.0, dz };
+                let sense = perp.dot(&perp) - k * dy.powi(2);
+                (sense, Vector { dx, dy: -k * dy, dz }.normalize())
+            }
+            CSGSurface::ZAxisCone { x, y, z, angle } => {
+                let k = angle.tan().powi(2);
+                let delta = *point - Point { x: *x, y: *y, z: *z };
+                let (dx, dy, dz) = (delta.dx, delta.dy, delta.dz);
+                let perp = Vector { dx, dy, dz: 0.0 };
+                let sense = perp.dot(&perp) - k * dz.powi(2);
+                (sense, Vector { dx, dy, dz: -k * dz }.normalize())
+            }
+            CSGSurface::Quadric { a, b, c, d, e, f, g, h, j, k } => {
+                let px = point.x;
+                let py = point.y;
+                let pz = point.z;
+                let sense = a * px.powi(2) + b * py.powi(2) + c * pz.powi(2) +
+                            d * px * py + e * py * pz + f * px * pz +
+                            g * px + h * py + j * pz + k;
+                let gradient = (
+                    2.0 * a * px + d * py + f * pz + g,
+                    2.0 * b * py + d * px + e * pz + h,
+                    2.0 * c * pz + e * py + f * px + j,
+                );
+                (sense, Vector { dx: gradient.0, dy: gradient.1, dz: gradient.2 }.normalize())
+            }
+            CSGSurface::XAxisTorus { x0, y0, z0, a, b: _, c } => {
+                let delta = *point - Point { x: *x0, y: *y0, z: *z0 };
+                let perp = Vector { dx: 0.0, dy: delta.dy, dz: delta.dz };
+                let s = delta.dot(&delta);
+                let q = a.powi(2) - c.powi(2);
+                let sense = (s + q).powi(2) - 4.0 * a.powi(2) * perp.dot(&perp);
+                let perp_term = s + q - 2.0 * a.powi(2);
+                (sense, Vector { dx: delta.dx * (s + q), dy: delta.dy * perp_term, dz: delta.dz * perp_term }.normalize())
+            }
+            CSGSurface::YAxisTorus { x0, y0, z0, a, b: _, c } => {
+                let delta = *point - Point { x: *x0, y: *y0, z: *z0 };
+                let perp = Vector { dx: delta.dx, dy: 0.0, dz: delta.dz };
+                let s = delta.dot(&delta);
+                let q = a.powi(2) - c.powi(2);
+                let sense = (s + q).powi(2) - 4.0 * a.powi(2) * perp.dot(&perp);
+                let perp_term = s + q - 2.0 * a.powi(2);
+                (sense, Vector { dx: delta.dx * perp_term, dy: delta.dy * (s + q), dz: delta.dz * perp_term }.normalize())
+            }
+            CSGSurface::ZAxisTorus { x0, y0, z0, a, b: _, c } => {
+                let delta = *point - Point { x: *x0, y: *y0, z: *z0 };
+                let perp = Vector { dx: delta.dx, dy: delta.dy, dz: 0.0 };
+                let s = delta.dot(&delta);
+                let q = a.powi(2) - c.powi(2);
+                let sense = (s + q).powi(2) - 4.0 * a.powi(2) * perp.dot(&perp);
+                let perp_term = s + q - 2.0 * a.powi(2);
+                (sense, Vector { dx: delta.dx * perp_term, dy: delta.dy * perp_term, dz: delta.dz * (s + q) }.normalize())
+            }
+        }
+    }
+
+    /// Which side of the surface `point` is on: `Less` inside, `Greater`
+    /// outside, `Equal` exactly on the surface.
+    pub fn side(&self, point: &Point) -> Ordering {
+        let (sense, _) = self.evaluate(point);
+        sense.partial_cmp(&0.0).unwrap_or(Ordering::Equal)
+    }
 }
 
 #[cfg(test)]
@@ -138,12 +729,61 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_vector_dot_and_cross() {
+        let a = Vector { dx: 1.0, dy: 0.0, dz: 0.0 };
+        let b = Vector { dx: 0.0, dy: 1.0, dz: 0.0 };
+        assert_eq!(a.dot(&b), 0.0);
+        assert_eq!(a.cross(&b), Vector { dx: 0.0, dy: 0.0, dz: 1.0 });
+    }
+
+    #[test]
+    fn test_vector_length_and_normalize() {
+        let v = Vector { dx: 3.0, dy: 4.0, dz: 0.0 };
+        assert_approx_eq(Some(v.length()), Some(5.0), EPSILON);
+        let normalized = v.normalize();
+        assert_approx_eq(Some(normalized.length()), Some(1.0), EPSILON);
+    }
+
+    #[test]
+    fn test_vector_arithmetic_ops() {
+        let a = Vector { dx: 1.0, dy: 2.0, dz: 3.0 };
+        let b = Vector { dx: 4.0, dy: 5.0, dz: 6.0 };
+        assert_eq!(a + b, Vector { dx: 5.0, dy: 7.0, dz: 9.0 });
+        assert_eq!(b - a, Vector { dx: 3.0, dy: 3.0, dz: 3.0 });
+        assert_eq!(a * 2.0, Vector { dx: 2.0, dy: 4.0, dz: 6.0 });
+        assert_eq!(b / 2.0, Vector { dx: 2.0, dy: 2.5, dz: 3.0 });
+    }
+
+    #[test]
+    fn test_point_vector_conversions() {
+        let p1 = Point { x: 4.0, y: 5.0, z: 6.0 };
+        let p2 = Point { x: 1.0, y: 1.0, z: 1.0 };
+        assert_eq!(p1 - p2, Vector { dx: 3.0, dy: 4.0, dz: 5.0 });
+        assert_eq!(p2 + Vector { dx: 3.0, dy: 4.0, dz: 5.0 }, p1);
+    }
+
     #[test]
     fn test_distance_to_sphere() {
+        let point = Point { x: 3.0, y: 1.0, z: 1.0 };
+        let vector = Vector { dx: -1.0, dy: 0.0, dz: 0.0 };
+        let surface = CSGSurface::Sphere { x: 1.0, y: 1.0, z: 1.0, radius: 1.0 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(1.0), EPSILON);
+    }
+
+    #[test]
+    fn test_no_intersection_with_sphere() {
+        let point = Point { x: 5.0, y: 1.0, z: 1.0 };
+        let vector = Vector { dx: 1.0, dy: 0.0, dz: 0.0 };
+        let surface = CSGSurface::Sphere { x: 1.0, y: 1.0, z: 1.0, radius: 1.0 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), None, EPSILON);
+    }
+
+    #[test]
+    fn test_nearest_distance_to_sphere() {
         let point = Point { x: 2.0, y: 2.0, z: 2.0 };
-        let vector = Vector { dx: 0.0, dy: 0.0, dz: 0.0 };
         let surface = CSGSurface::Sphere { x: 1.0, y: 1.0, z: 1.0, radius: 1.0 };
-        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some((3.0_f64).sqrt() - 1.0), EPSILON);
+        assert_approx_eq(Some(surface.nearest_distance(&point)), Some((3.0_f64).sqrt() - 1.0), EPSILON);
     }
 
     #[test]
@@ -202,67 +842,265 @@ mod tests {
         assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(0.0), EPSILON);
     }
 
+    #[test]
+    fn test_distance_to_plane_off_plane() {
+        // Point not on the plane, with a non-unit direction along the plane's
+        // normal: the ray parameter is t = 1 while the nearest-point distance
+        // is sqrt(3), so this can only pass if the ray is actually solved for
+        // `t` rather than the nearest-point distance being returned.
+        let point = Point { x: 0.0, y: 0.0, z: 0.0 };
+        let vector = Vector { dx: 1.0, dy: 1.0, dz: 1.0 };
+        let surface = CSGSurface::Plane { a: 1.0, b: 1.0, c: 1.0, d: -3.0 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(1.0), EPSILON);
+    }
+
+    #[test]
+    fn test_distance_to_plane_parallel_ray_misses() {
+        let point = Point { x: 0.0, y: 0.0, z: 0.0 };
+        let vector = Vector { dx: 1.0, dy: -1.0, dz: 0.0 };
+        let surface = CSGSurface::Plane { a: 1.0, b: 1.0, c: 1.0, d: -3.0 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), None, EPSILON);
+    }
+
     #[test]
     fn test_distance_to_x_axis_cylinder() {
+        let point = Point { x: 5.0, y: 3.0, z: 0.0 };
+        let vector = Vector { dx: 0.0, dy: -1.0, dz: 0.0 };
+        let surface = CSGSurface::XAxisCylinder { radius: 1.0 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(2.0), EPSILON);
+    }
+
+    #[test]
+    fn test_nearest_distance_to_x_axis_cylinder() {
         let point = Point { x: 1.0, y: 2.0, z: 2.0 };
-        let vector = Vector { dx: 1.0, dy: 1.0, dz: 1.0 };
         let surface = CSGSurface::XAxisCylinder { radius: 1.0 };
-        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some((8.0_f64).sqrt() - 1.0), EPSILON);
+        assert_approx_eq(Some(surface.nearest_distance(&point)), Some((8.0_f64).sqrt() - 1.0), EPSILON);
     }
 
     #[test]
     fn test_distance_to_y_axis_cylinder() {
+        let point = Point { x: 3.0, y: 7.0, z: 0.0 };
+        let vector = Vector { dx: -1.0, dy: 0.0, dz: 0.0 };
+        let surface = CSGSurface::YAxisCylinder { radius: 1.0 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(2.0), EPSILON);
+    }
+
+    #[test]
+    fn test_nearest_distance_to_y_axis_cylinder() {
         let point = Point { x: 2.0, y: 1.0, z: 2.0 };
-        let vector = Vector { dx: 1.0, dy: 1.0, dz: 1.0 };
         let surface = CSGSurface::YAxisCylinder { radius: 1.0 };
-        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some((8.0_f64).sqrt() - 1.0), EPSILON);
+        assert_approx_eq(Some(surface.nearest_distance(&point)), Some((8.0_f64).sqrt() - 1.0), EPSILON);
     }
 
     #[test]
     fn test_distance_to_z_axis_cylinder() {
+        let point = Point { x: 3.0, y: 0.0, z: 9.0 };
+        let vector = Vector { dx: -1.0, dy: 0.0, dz: 0.0 };
+        let surface = CSGSurface::ZAxisCylinder { radius: 1.0 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(2.0), EPSILON);
+    }
+
+    #[test]
+    fn test_nearest_distance_to_z_axis_cylinder() {
         let point = Point { x: 2.0, y: 2.0, z: 1.0 };
-        let vector = Vector { dx: 1.0, dy: 1.0, dz: 1.0 };
         let surface = CSGSurface::ZAxisCylinder { radius: 1.0 };
-        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some((8.0_f64).sqrt() - 1.0), EPSILON);
+        assert_approx_eq(Some(surface.nearest_distance(&point)), Some((8.0_f64).sqrt() - 1.0), EPSILON);
     }
 
     #[test]
     fn test_distance_to_x_axis_cone() {
+        let point = Point { x: 5.0, y: 2.0, z: 0.0 };
+        let vector = Vector { dx: 0.0, dy: -1.0, dz: 0.0 };
+        let surface = CSGSurface::XAxisCone { x: 0.0, y: 0.0, z: 0.0, angle: std::f64::consts::FRAC_PI_4 }; // 45 degrees
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(7.0), EPSILON);
+    }
+
+    #[test]
+    fn test_nearest_distance_to_x_axis_cone() {
         let point = Point { x: 1.0, y: 2.0, z: 2.0 };
-        let vector = Vector { dx: 1.0, dy: 1.0, dz: 1.0 };
         let surface = CSGSurface::XAxisCone { x: 0.0, y: 0.0, z: 0.0, angle: std::f64::consts::FRAC_PI_4 }; // 45 degrees
-        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some((8.0_f64).sqrt()), EPSILON);
+        assert_approx_eq(Some(surface.nearest_distance(&point)), Some((8.0_f64).sqrt() - 1.0), EPSILON);
     }
 
     #[test]
     fn test_distance_to_y_axis_cone() {
+        let point = Point { x: 2.0, y: 5.0, z: 0.0 };
+        let vector = Vector { dx: -1.0, dy: 0.0, dz: 0.0 };
+        let surface = CSGSurface::YAxisCone { x: 0.0, y: 0.0, z: 0.0, angle: std::f64::consts::FRAC_PI_4 }; // 45 degrees
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(7.0), EPSILON);
+    }
+
+    #[test]
+    fn test_nearest_distance_to_y_axis_cone() {
         let point = Point { x: 2.0, y: 1.0, z: 2.0 };
-        let vector = Vector { dx: 1.0, dy: 1.0, dz: 1.0 };
         let surface = CSGSurface::YAxisCone { x: 0.0, y: 0.0, z: 0.0, angle: std::f64::consts::FRAC_PI_4 }; // 45 degrees
-        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some((8.0_f64).sqrt()), EPSILON);
+        assert_approx_eq(Some(surface.nearest_distance(&point)), Some((8.0_f64).sqrt() - 1.0), EPSILON);
     }
 
     #[test]
     fn test_distance_to_z_axis_cone() {
+        let point = Point { x: 2.0, y: 0.0, z: 5.0 };
+        let vector = Vector { dx: -1.0, dy: 0.0, dz: 0.0 };
+        let surface = CSGSurface::ZAxisCone { x: 0.0, y: 0.0, z: 0.0, angle: std::f64::consts::FRAC_PI_4 }; // 45 degrees
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(7.0), EPSILON);
+    }
+
+    #[test]
+    fn test_nearest_distance_to_z_axis_cone() {
         let point = Point { x: 2.0, y: 2.0, z: 1.0 };
-        let vector = Vector { dx: 1.0, dy: 1.0, dz: 1.0 };
         let surface = CSGSurface::ZAxisCone { x: 0.0, y: 0.0, z: 0.0, angle: std::f64::consts::FRAC_PI_4 }; // 45 degrees
-        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some((8.0_f64).sqrt()), EPSILON);
+        assert_approx_eq(Some(surface.nearest_distance(&point)), Some((8.0_f64).sqrt() - 1.0), EPSILON);
     }
 
     #[test]
     fn test_distance_to_quadric() {
+        let point = Point { x: 3.0, y: 0.0, z: 0.0 };
+        let vector = Vector { dx: -1.0, dy: 0.0, dz: 0.0 };
+        let surface = CSGSurface::Quadric { a: 1.0, b: 1.0, c: 1.0, d: 0.0, e: 0.0, f: 0.0, g: 0.0, h: 0.0, j: 0.0, k: -3.0 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(3.0 - (3.0_f64).sqrt()), EPSILON);
+    }
+
+    #[test]
+    fn test_no_intersection_with_quadric() {
+        let point = Point { x: 3.0, y: 0.0, z: 0.0 };
+        let vector = Vector { dx: 1.0, dy: 0.0, dz: 0.0 };
+        let surface = CSGSurface::Quadric { a: 1.0, b: 1.0, c: 1.0, d: 0.0, e: 0.0, f: 0.0, g: 0.0, h: 0.0, j: 0.0, k: -3.0 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), None, EPSILON);
+    }
+
+    #[test]
+    fn test_nearest_distance_to_quadric() {
         let point = Point { x: 1.0, y: 1.0, z: 1.0 };
-        let vector = Vector { dx: 1.0, dy: 1.0, dz: 1.0 };
         let surface = CSGSurface::Quadric { a: 1.0, b: 1.0, c: 1.0, d: 0.0, e: 0.0, f: 0.0, g: 0.0, h: 0.0, j: 0.0, k: -3.0 };
-        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(0.0), EPSILON);
+        assert_approx_eq(Some(surface.nearest_distance(&point)), Some(0.0), EPSILON);
+    }
+
+    #[test]
+    fn test_distance_to_degenerate_quadric() {
+        // a = b = c = d = e = f = 0 reduces to the linear plane x - 2 = 0.
+        let point = Point { x: 1.0, y: 1.0, z: 1.0 };
+        let vector = Vector { dx: 1.0, dy: 0.0, dz: 0.0 };
+        let surface = CSGSurface::Quadric { a: 0.0, b: 0.0, c: 0.0, d: 0.0, e: 0.0, f: 0.0, g: 1.0, h: 0.0, j: 0.0, k: -2.0 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(1.0), EPSILON);
     }
 
     #[test]
     fn test_distance_to_x_axis_torus() {
+        let point = Point { x: 0.0, y: 5.0, z: 0.0 };
+        let vector = Vector { dx: 0.0, dy: -1.0, dz: 0.0 };
+        let surface = CSGSurface::XAxisTorus { x0: 0.0, y0: 0.0, z0: 0.0, a: 2.0, b: 0.5, c: 0.5 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(2.5), EPSILON);
+    }
+
+    #[test]
+    fn test_nearest_distance_to_x_axis_torus() {
         let point = Point { x: 2.0, y: 2.0, z: 2.0 };
-        let vector = Vector { dx: 1.0, dy: 1.0, dz: 1.0 };
         let surface = CSGSurface::XAxisTorus { x0: 0.0, y0: 0.0, z0: 0.0, a: 1.0, b: 0.5, c: 0.5 };
-        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some((2.0_f64).sqrt() - 0.5), EPSILON);
+        let expected = (((8.0_f64).sqrt() - 1.0).powi(2) + 4.0).sqrt() - 0.5;
+        assert_approx_eq(Some(surface.nearest_distance(&point)), Some(expected), EPSILON);
+    }
+
+    #[test]
+    fn test_distance_to_y_axis_torus() {
+        let point = Point { x: 5.0, y: 0.0, z: 0.0 };
+        let vector = Vector { dx: -1.0, dy: 0.0, dz: 0.0 };
+        let surface = CSGSurface::YAxisTorus { x0: 0.0, y0: 0.0, z0: 0.0, a: 2.0, b: 0.5, c: 0.5 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(2.5), EPSILON);
+    }
+
+    #[test]
+    fn test_nearest_distance_to_y_axis_torus() {
+        let point = Point { x: 2.0, y: 2.0, z: 2.0 };
+        let surface = CSGSurface::YAxisTorus { x0: 0.0, y0: 0.0, z0: 0.0, a: 1.0, b: 0.5, c: 0.5 };
+        let expected = (((8.0_f64).sqrt() - 1.0).powi(2) + 4.0).sqrt() - 0.5;
+        assert_approx_eq(Some(surface.nearest_distance(&point)), Some(expected), EPSILON);
+    }
+
+    #[test]
+    fn test_distance_to_z_axis_torus() {
+        let point = Point { x: 5.0, y: 0.0, z: 0.0 };
+        let vector = Vector { dx: -1.0, dy: 0.0, dz: 0.0 };
+        let surface = CSGSurface::ZAxisTorus { x0: 0.0, y0: 0.0, z0: 0.0, a: 2.0, b: 0.5, c: 0.5 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(2.5), EPSILON);
+    }
+
+    #[test]
+    fn test_distance_to_z_axis_torus_off_symmetry_plane() {
+        // A ray that leaves the torus's planes of symmetry (dz != 0 here)
+        // exercises the general Ferrari branch of solve_depressed_quartic,
+        // not just the q ~ 0 biquadratic shortcut the other torus tests hit.
+        let point = Point { x: 0.768, y: 1.692, z: 0.426 };
+        let vector = Vector { dx: -0.702, dy: 0.705, dz: 0.103 };
+        let surface = CSGSurface::ZAxisTorus { x0: 0.0, y0: 0.0, z0: 0.0, a: 3.0, b: 0.0, c: 1.0 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), Some(0.5688494751526951), 1e-6);
+    }
+
+    #[test]
+    fn test_nearest_distance_to_z_axis_torus() {
+        let point = Point { x: 2.0, y: 2.0, z: 2.0 };
+        let surface = CSGSurface::ZAxisTorus { x0: 0.0, y0: 0.0, z0: 0.0, a: 1.0, b: 0.5, c: 0.5 };
+        let expected = (((8.0_f64).sqrt() - 1.0).powi(2) + 4.0).sqrt() - 0.5;
+        assert_approx_eq(Some(surface.nearest_distance(&point)), Some(expected), EPSILON);
+    }
+
+    #[test]
+    fn test_no_intersection_with_x_axis_torus() {
+        let point = Point { x: 0.0, y: 5.0, z: 0.0 };
+        let vector = Vector { dx: 0.0, dy: 1.0, dz: 0.0 };
+        let surface = CSGSurface::XAxisTorus { x0: 0.0, y0: 0.0, z0: 0.0, a: 2.0, b: 0.5, c: 0.5 };
+        assert_approx_eq(surface.distance_to_surface(&point, &vector), None, EPSILON);
+    }
+
+    #[test]
+    fn test_evaluate_sphere() {
+        let surface = CSGSurface::Sphere { x: 0.0, y: 0.0, z: 0.0, radius: 1.0 };
+        let (sense, normal) = surface.evaluate(&Point { x: 2.0, y: 0.0, z: 0.0 });
+        assert!(sense > 0.0);
+        assert!((normal.dx - 1.0).abs() < EPSILON);
+        assert!(normal.dy.abs() < EPSILON);
+        assert!(normal.dz.abs() < EPSILON);
+    }
+
+    #[test]
+    fn test_evaluate_x_plane() {
+        let surface = CSGSurface::XPlane { x: 5.0 };
+        let (sense, normal) = surface.evaluate(&Point { x: 3.0, y: 0.0, z: 0.0 });
+        assert_approx_eq(Some(sense), Some(-2.0), EPSILON);
+        assert_approx_eq(Some(normal.dx), Some(1.0), EPSILON);
+    }
+
+    #[test]
+    fn test_evaluate_z_axis_cylinder() {
+        let surface = CSGSurface::ZAxisCylinder { radius: 1.0 };
+        let (sense, normal) = surface.evaluate(&Point { x: 0.0, y: 2.0, z: 7.0 });
+        assert!(sense > 0.0);
+        assert_approx_eq(Some(normal.dy), Some(1.0), EPSILON);
+        assert_approx_eq(Some(normal.dz), Some(0.0), EPSILON);
+    }
+
+    #[test]
+    fn test_evaluate_quadric() {
+        // x^2 + y^2 + z^2 - 1 = 0, a unit sphere in quadric form.
+        let surface = CSGSurface::Quadric { a: 1.0, b: 1.0, c: 1.0, d: 0.0, e: 0.0, f: 0.0, g: 0.0, h: 0.0, j: 0.0, k: -1.0 };
+        let (sense, normal) = surface.evaluate(&Point { x: 0.0, y: 0.0, z: 0.5 });
+        assert!(sense < 0.0);
+        assert_approx_eq(Some(normal.dz), Some(1.0), EPSILON);
+    }
+
+    #[test]
+    fn test_evaluate_x_axis_torus() {
+        let surface = CSGSurface::XAxisTorus { x0: 0.0, y0: 0.0, z0: 0.0, a: 2.0, b: 0.5, c: 0.5 };
+        // On the ring's outer edge, in the y-z plane at x = x0.
+        let (sense, normal) = surface.evaluate(&Point { x: 0.0, y: 2.5, z: 0.0 });
+        assert_approx_eq(Some(sense), Some(0.0), EPSILON);
+        assert_approx_eq(Some(normal.dy), Some(1.0), EPSILON);
+    }
+
+    #[test]
+    fn test_side() {
+        let surface = CSGSurface::Sphere { x: 0.0, y: 0.0, z: 0.0, radius: 1.0 };
+        assert_eq!(surface.side(&Point { x: 0.0, y: 0.0, z: 0.0 }), Ordering::Less);
+        assert_eq!(surface.side(&Point { x: 5.0, y: 0.0, z: 0.0 }), Ordering::Greater);
+        assert_eq!(surface.side(&Point { x: 1.0, y: 0.0, z: 0.0 }), Ordering::Equal);
     }
 }
\ No newline at end of file